@@ -115,13 +115,22 @@ fn random_function() {
 #[cfg(test)]
 mod tests;
 
+use std::any::{Any, TypeId};
 use std::collections::HashMap;
+use std::error::Error;
 #[cfg(not(feature = "tokio_thread"))]
 use std::thread;
 #[cfg(feature = "tokio_thread")]
 use tokio::task as thread;
-use std::sync::Arc;
+use std::future::Future;
+#[cfg(feature = "tokio_thread")]
+use std::pin::Pin;
+use std::marker::PhantomData;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "tokio_thread")]
+use futures::stream::{FuturesUnordered, StreamExt};
 
 // #[macro_use]
 // extern crate lazy_static;
@@ -130,15 +139,115 @@ use bincode;
 
 use uuid::Uuid;
 
+/// Signals to `sync_emit` whether remaining, lower-priority listeners for an event
+/// should still run or whether dispatch should stop immediately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Propagation {
+    /// Carry on to the next listener (in priority order).
+    Continue,
+    /// Stop calling any further listeners for this emission.
+    Stop,
+}
+
 pub struct Listener {
-    callback: Arc<dyn Fn(Vec<u8>) + Sync + Send + 'static>,
+    callback: Arc<dyn Fn(Vec<u8>) -> Propagation + Sync + Send + 'static>,
     limit: Option<u64>,
     id: String,
+    priority: i32,
 }
 
 #[derive(Default)]
 pub struct EventEmitter {
-    pub listeners: HashMap<String, Vec<Listener>>
+    pub listeners: HashMap<String, Vec<Listener>>,
+    channels: Arc<Mutex<HashMap<String, Vec<(String, Sender<Vec<u8>>)>>>>,
+    typed_listeners: HashMap<(String, TypeId), Vec<(String, Arc<dyn Fn(&dyn Any) + Sync + Send>)>>,
+    #[cfg(feature = "tokio_thread")]
+    async_listeners: HashMap<String, Vec<AsyncListener>>,
+    try_listeners: HashMap<String, Vec<TryListener>>,
+}
+
+/// An event listener whose callback can fail - See [`EventEmitter::on_try`] and [`EventEmitter::try_emit`].
+pub struct TryListener {
+    callback: Arc<dyn Fn(Vec<u8>) -> Result<(), Box<dyn Error + Send>> + Sync + Send>,
+    id: String,
+}
+
+/// The outcome of dispatching an event with [`EventEmitter::try_emit`].
+pub struct TryEmitResult {
+    /// How many `on_try` listeners were invoked for this emission.
+    pub listeners_invoked: usize,
+    /// The errors returned by any of those listeners, in the order the listeners were added -
+    /// A listener that panics is reported here too, as a [`ListenerPanicked`] error.
+    pub errors: Vec<Box<dyn Error + Send>>,
+}
+
+/// Reported in [`TryEmitResult::errors`] when an `on_try` listener panicked instead of returning `Err`.
+#[derive(Debug)]
+pub struct ListenerPanicked;
+
+impl std::fmt::Display for ListenerPanicked {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "listener panicked")
+    }
+}
+
+impl Error for ListenerPanicked {}
+
+/// An event listener whose callback produces a future to be driven by [`EventEmitter::emit_async`]
+/// instead of a plain closure run on a spawned thread - See [`EventEmitter::on_async`].
+#[cfg(feature = "tokio_thread")]
+pub struct AsyncListener {
+    callback: Arc<dyn Fn(Vec<u8>) -> Pin<Box<dyn Future<Output = ()> + Send>> + Sync + Send>,
+    id: String,
+}
+
+/// A pull-based subscription to an event, created with [`EventEmitter::register`].
+///
+/// Unlike the callback-based listeners added via `on`, an `EventListener` doesn't run any code when
+/// the event fires - Instead it buffers each emitted value on an internal channel for the caller to
+/// `recv`/`try_recv` at their own pace, which suits actor-style or select-loop code better than a closure.
+pub struct EventListener<T> {
+    id: String,
+    event: String,
+    receiver: Receiver<Vec<u8>>,
+    channels: Arc<Mutex<HashMap<String, Vec<(String, Sender<Vec<u8>>)>>>>,
+    _value: PhantomData<T>,
+}
+
+impl<T> EventListener<T>
+    where for<'de> T: Deserialize<'de>
+{
+    /// Blocks the calling thread until the subscribed event is next emitted, then returns the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_emitter_rs::EventEmitter;
+    /// let mut event_emitter = EventEmitter::new();
+    ///
+    /// let listener = event_emitter.register::<u32>("Some event");
+    /// event_emitter.emit("Some event", 10 as u32);
+    /// assert_eq!(10, listener.recv());
+    /// ```
+    pub fn recv(&self) -> T {
+        let bytes = self.receiver.recv().unwrap();
+        return bincode::deserialize(&bytes).unwrap();
+    }
+
+    /// Returns the next emitted value without blocking, or `None` if none is currently available.
+    pub fn try_recv(&self) -> Option<T> {
+        let bytes = self.receiver.try_recv().ok()?;
+        return bincode::deserialize(&bytes).ok();
+    }
+}
+
+impl<T> Drop for EventListener<T> {
+    fn drop(&mut self) {
+        let mut channels = self.channels.lock().unwrap();
+        if let Some(senders) = channels.get_mut(&self.event) {
+            senders.retain(|(id, _)| id != &self.id);
+        }
+    }
 }
 
 impl EventEmitter {
@@ -188,10 +297,15 @@ impl EventEmitter {
         where T: Serialize
     {
         let mut callback_handlers: Vec<thread::JoinHandle<()>> = Vec::new();
+        let bytes: Vec<u8> = bincode::serialize(&value).unwrap();
+
+        if let Some(senders) = self.channels.lock().unwrap().get(event) {
+            for (_, sender) in senders {
+                let _ = sender.send(bytes.clone());
+            }
+        }
 
         if let Some(listeners) = self.listeners.get_mut(event) {
-            let bytes: Vec<u8> = bincode::serialize(&value).unwrap();
-            
             let mut listeners_to_remove: Vec<usize> = Vec::new();
             for (index, listener) in listeners.iter_mut().enumerate() {
                 let cloned_bytes = bytes.clone();
@@ -254,7 +368,29 @@ impl EventEmitter {
             if let Some(index) = event_listeners.iter().position(|listener| listener.id == id_to_delete) {
                 event_listeners.remove(index);
                 return Some(id_to_delete.to_string());
-            } 
+            }
+        }
+
+        for (_, typed_listeners) in self.typed_listeners.iter_mut() {
+            if let Some(index) = typed_listeners.iter().position(|(id, _)| id == id_to_delete) {
+                typed_listeners.remove(index);
+                return Some(id_to_delete.to_string());
+            }
+        }
+
+        for (_, try_listeners) in self.try_listeners.iter_mut() {
+            if let Some(index) = try_listeners.iter().position(|listener| listener.id == id_to_delete) {
+                try_listeners.remove(index);
+                return Some(id_to_delete.to_string());
+            }
+        }
+
+        #[cfg(feature = "tokio_thread")]
+        for (_, async_listeners) in self.async_listeners.iter_mut() {
+            if let Some(index) = async_listeners.iter().position(|listener| listener.id == id_to_delete) {
+                async_listeners.remove(index);
+                return Some(id_to_delete.to_string());
+            }
         }
 
         return None;
@@ -277,30 +413,95 @@ impl EventEmitter {
     /// event_emitter.emit("Some event", ()); // 4 >> <Nothing happens here because listener was deleted after the 3rd call>
     /// ```
     pub fn on_limited<F, T>(&mut self, event: &str, limit: Option<u64>, callback: F) -> String
-        where 
+        where
             for<'de> T: Deserialize<'de>,
-            F: Fn(T) + 'static + Sync + Send 
+            F: Fn(T) + 'static + Sync + Send
+    {
+        let id = self.add_listener(event, limit, 0, callback);
+        return id;
+    }
+
+    /// Adds an event listener with a given priority whose callback controls propagation - Listeners
+    /// with a higher priority are called before listeners with a lower priority when using
+    /// [`EventEmitter::sync_emit`], and a callback can return [`Propagation::Stop`] to prevent any
+    /// remaining, lower-priority listeners from being called for that emission.
+    /// Returns the id of the newly added listener.
+    ///
+    /// Since `emit` always calls every listener on its own spawned thread, priority and propagation
+    /// only take effect when dispatching through `sync_emit`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_emitter_rs::{EventEmitter, Propagation};
+    /// let mut event_emitter = EventEmitter::new();
+    ///
+    /// event_emitter.on_with_priority("Some event", 10, |_: ()| {
+    ///     println!("Runs first, and stops anything lower priority from running");
+    ///     Propagation::Stop
+    /// });
+    /// event_emitter.on_with_priority("Some event", 0, |_: ()| {
+    ///     println!("Never runs since the higher priority listener stopped propagation");
+    ///     Propagation::Continue
+    /// });
+    /// event_emitter.sync_emit("Some event", ());
+    /// ```
+    pub fn on_with_priority<F, T>(&mut self, event: &str, priority: i32, callback: F) -> String
+        where
+            for<'de> T: Deserialize<'de>,
+            F: Fn(T) -> Propagation + 'static + Sync + Send
     {
         let id = Uuid::new_v4().to_string();
-        let parsed_callback = move |bytes: Vec<u8>| {
+        let parsed_callback = move |bytes: Vec<u8>| -> Propagation {
+            let value: T = bincode::deserialize(&bytes).unwrap();
+            callback(value)
+        };
+
+        let listener = Listener {
+            id: id.clone(),
+            limit: None,
+            priority,
+            callback: Arc::new(parsed_callback),
+        };
+        self.insert_listener(event, listener);
+
+        return id;
+    }
+
+    /// Shared implementation backing `on_limited` (and transitively `on`/`once`) - Wraps the user's
+    /// callback (which returns nothing) so it always reports [`Propagation::Continue`], then inserts
+    /// the listener into the event's `Vec` keeping it sorted by descending priority.
+    fn add_listener<F, T>(&mut self, event: &str, limit: Option<u64>, priority: i32, callback: F) -> String
+        where
+            for<'de> T: Deserialize<'de>,
+            F: Fn(T) + 'static + Sync + Send
+    {
+        let id = Uuid::new_v4().to_string();
+        let parsed_callback = move |bytes: Vec<u8>| -> Propagation {
             let value: T = bincode::deserialize(&bytes).unwrap();
             callback(value);
+            Propagation::Continue
         };
 
         let listener = Listener {
             id: id.clone(),
             limit,
+            priority,
             callback: Arc::new(parsed_callback),
         };
-
-        match self.listeners.get_mut(event) {
-            Some(callbacks) => { callbacks.push(listener); },
-            None => { self.listeners.insert(event.to_string(), vec![listener]); }
-        }
+        self.insert_listener(event, listener);
 
         return id;
     }
 
+    /// Inserts a listener for `event`, keeping the `Vec` sorted by descending priority (ties are
+    /// broken by insertion order) so `sync_emit` can walk it directly.
+    fn insert_listener(&mut self, event: &str, listener: Listener) {
+        let callbacks = self.listeners.entry(event.to_string()).or_insert_with(Vec::new);
+        let insert_at = callbacks.iter().position(|existing| existing.priority < listener.priority).unwrap_or(callbacks.len());
+        callbacks.insert(insert_at, listener);
+    }
+
     /// Adds an event listener that will only execute the callback once - Then the listener will be deleted.
     /// Returns the id of the newly added listener.
     ///
@@ -326,9 +527,282 @@ impl EventEmitter {
         return id;
     }
 
-    /// NOT IMPLEMENTED!
+    /// Registers a pull-based subscription to an event, returning an [`EventListener`] instead of
+    /// taking a callback - Every time `event` is emitted afterwards, the serialized value is pushed
+    /// onto the listener's internal channel for the caller to `recv`/`try_recv` in their own loop.
+    ///
+    /// This suits actor-style or `select!`-driven code better than a closure, since the caller decides
+    /// when to pull the next value rather than having it pushed into a callback on another thread.
+    /// The returned `EventListener` deregisters itself when dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_emitter_rs::EventEmitter;
+    /// let mut event_emitter = EventEmitter::new();
+    ///
+    /// let listener = event_emitter.register::<String>("Some event");
+    /// event_emitter.emit("Some event", "Hello programmer!".to_string());
+    /// assert_eq!("Hello programmer!".to_string(), listener.recv());
+    /// ```
+    pub fn register<T>(&mut self, event: &str) -> EventListener<T>
+        where for<'de> T: Deserialize<'de>
+    {
+        let id = Uuid::new_v4().to_string();
+        let (sender, receiver) = channel();
+
+        self.channels.lock().unwrap()
+            .entry(event.to_string())
+            .or_insert_with(Vec::new)
+            .push((id.clone(), sender));
+
+        EventListener {
+            id,
+            event: event.to_string(),
+            receiver,
+            channels: Arc::clone(&self.channels),
+            _value: PhantomData,
+        }
+    }
+
+    /// Adds a zero-serialization listener for same-process dispatch via [`EventEmitter::emit_typed`] -
+    /// Unlike `on`, the callback receives a `&T` directly instead of a bincode round-trip, so this is
+    /// the listener to reach for when the emitter and its listeners live in the same process and the
+    /// clone+serialize+deserialize cost of `emit` is overhead you don't need to pay.
+    /// Returns the id of the newly added listener, which can be passed to [`EventEmitter::remove_listener`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_emitter_rs::EventEmitter;
+    /// let mut event_emitter = EventEmitter::new();
+    ///
+    /// event_emitter.on_typed("Some event", |value: &u32| println!("{}", value));
+    /// event_emitter.emit_typed("Some event", 5 as u32);
+    /// // >> "5"
+    /// ```
+    pub fn on_typed<F, T>(&mut self, event: &str, callback: F) -> String
+        where
+            T: 'static,
+            F: Fn(&T) + 'static + Sync + Send
+    {
+        let id = Uuid::new_v4().to_string();
+        let parsed_callback = move |value: &dyn Any| {
+            callback(value.downcast_ref::<T>().unwrap());
+        };
+
+        self.typed_listeners
+            .entry((event.to_string(), TypeId::of::<T>()))
+            .or_insert_with(Vec::new)
+            .push((id.clone(), Arc::new(parsed_callback)));
+
+        return id;
+    }
+
+    /// Emits an event to any listeners added with [`EventEmitter::on_typed`] for the same type `T`,
+    /// calling each one directly on the calling thread with a `&T` - No bincode serialization takes
+    /// place, so this only reaches typed listeners; plain `on`/`on_limited`/`once` listeners for the
+    /// same event are untouched and need `emit`/`sync_emit` instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_emitter_rs::EventEmitter;
+    /// let mut event_emitter = EventEmitter::new();
+    ///
+    /// event_emitter.on_typed("Some event", |value: &u32| println!("{}", value));
+    /// event_emitter.emit_typed("Some event", 5 as u32);
+    /// // >> "5"
+    /// ```
+    pub fn emit_typed<T>(&mut self, event: &str, value: T)
+        where T: 'static + Clone
+    {
+        if let Some(callbacks) = self.typed_listeners.get(&(event.to_string(), TypeId::of::<T>())) {
+            for (_, callback) in callbacks {
+                callback(&value);
+            }
+        }
+    }
+
+    /// Adds a genuinely async event listener whose callback returns a future instead of running
+    /// synchronously on a spawned OS thread - Use [`EventEmitter::emit_async`] to drive it. This is
+    /// only available behind the `tokio_thread` feature.
+    /// Returns the id of the newly added listener.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use event_emitter_rs::EventEmitter;
+    /// let mut event_emitter = EventEmitter::new();
+    ///
+    /// event_emitter.on_async("Some event", |value: u32| async move {
+    ///     println!("{}", value);
+    /// });
+    /// event_emitter.emit_async("Some event", 5 as u32).await;
+    /// ```
+    #[cfg(feature = "tokio_thread")]
+    pub fn on_async<F, Fut, T>(&mut self, event: &str, callback: F) -> String
+        where
+            for<'de> T: Deserialize<'de>,
+            F: Fn(T) -> Fut + 'static + Sync + Send,
+            Fut: Future<Output = ()> + Send + 'static
+    {
+        let id = Uuid::new_v4().to_string();
+        let parsed_callback = move |bytes: Vec<u8>| -> Pin<Box<dyn Future<Output = ()> + Send>> {
+            let value: T = bincode::deserialize(&bytes).unwrap();
+            Box::pin(callback(value))
+        };
+
+        let listener = AsyncListener {
+            id: id.clone(),
+            callback: Arc::new(parsed_callback),
+        };
+
+        self.async_listeners
+            .entry(event.to_string())
+            .or_insert_with(Vec::new)
+            .push(listener);
+
+        return id;
+    }
+
+    /// Emits an event to every listener added with [`EventEmitter::on_async`], driving all of their
+    /// futures concurrently with a `FuturesUnordered` and resolving once every one has completed -
+    /// Unlike `emit`, no OS thread is spawned per listener; the async work runs on the caller's executor.
+    /// Only available behind the `tokio_thread` feature.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use event_emitter_rs::EventEmitter;
+    /// let mut event_emitter = EventEmitter::new();
+    ///
+    /// event_emitter.on_async("Some event", |value: u32| async move {
+    ///     println!("{}", value);
+    /// });
+    /// event_emitter.emit_async("Some event", 5 as u32).await;
+    /// ```
+    #[cfg(feature = "tokio_thread")]
+    pub async fn emit_async<T>(&self, event: &str, value: T)
+        where T: Serialize
+    {
+        if let Some(listeners) = self.async_listeners.get(event) {
+            let bytes: Vec<u8> = bincode::serialize(&value).unwrap();
+
+            let mut futures = FuturesUnordered::new();
+            for listener in listeners.iter() {
+                futures.push((listener.callback)(bytes.clone()));
+            }
+
+            while let Some(_) = futures.next().await {}
+        }
+    }
+
+    /// Adds an event listener whose callback can fail - Use [`EventEmitter::try_emit`] to dispatch to
+    /// it and find out whether any listener returned an error. A plain `on` listener that panics or
+    /// returns nothing gives the caller no way to know a listener failed; `on_try` lets callers of
+    /// `try_emit` react to failures instead of losing them silently on a spawned thread.
+    /// Returns the id of the newly added listener.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_emitter_rs::EventEmitter;
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct MyError;
+    /// impl fmt::Display for MyError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "something went wrong") }
+    /// }
+    /// impl std::error::Error for MyError {}
+    ///
+    /// let mut event_emitter = EventEmitter::new();
+    /// event_emitter.on_try("Some event", |_: ()| Err(MyError));
+    /// let result = event_emitter.try_emit("Some event", ());
+    /// assert_eq!(1, result.listeners_invoked);
+    /// assert_eq!(1, result.errors.len());
+    /// ```
+    pub fn on_try<F, T, E>(&mut self, event: &str, callback: F) -> String
+        where
+            for<'de> T: Deserialize<'de>,
+            F: Fn(T) -> Result<(), E> + 'static + Sync + Send,
+            E: Error + Send + 'static
+    {
+        let id = Uuid::new_v4().to_string();
+        let parsed_callback = move |bytes: Vec<u8>| -> Result<(), Box<dyn Error + Send>> {
+            let value: T = bincode::deserialize(&bytes).unwrap();
+            callback(value).map_err(|error| Box::new(error) as Box<dyn Error + Send>)
+        };
+
+        let listener = TryListener {
+            id: id.clone(),
+            callback: Arc::new(parsed_callback),
+        };
+
+        self.try_listeners
+            .entry(event.to_string())
+            .or_insert_with(Vec::new)
+            .push(listener);
+
+        return id;
+    }
+
+    /// Emits an event to every listener added with [`EventEmitter::on_try`], running each on its own
+    /// spawned thread (as `emit` does) but joining them all and aggregating the outcome, instead of
+    /// letting a failing or panicking callback disappear silently.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_emitter_rs::EventEmitter;
+    /// let mut event_emitter = EventEmitter::new();
+    ///
+    /// event_emitter.on_try("Some event", |_: ()| -> Result<(), std::io::Error> { Ok(()) });
+    /// let result = event_emitter.try_emit("Some event", ());
+    /// assert_eq!(1, result.listeners_invoked);
+    /// assert!(result.errors.is_empty());
+    /// ```
+    pub fn try_emit<T>(&self, event: &str, value: T) -> TryEmitResult
+        where T: Serialize
+    {
+        let mut result = TryEmitResult { listeners_invoked: 0, errors: Vec::new() };
+
+        if let Some(listeners) = self.try_listeners.get(event) {
+            let bytes: Vec<u8> = bincode::serialize(&value).unwrap();
+            let mut handles = Vec::new();
+
+            for listener in listeners.iter() {
+                let cloned_bytes = bytes.clone();
+                let callback = Arc::clone(&listener.callback);
+                handles.push(std::thread::spawn(move || callback(cloned_bytes)));
+            }
+
+            result.listeners_invoked = handles.len();
+            for handle in handles {
+                match handle.join() {
+                    Ok(Ok(())) => {},
+                    Ok(Err(error)) => result.errors.push(error),
+                    Err(_panic) => result.errors.push(Box::new(ListenerPanicked)),
+                }
+            }
+        }
+
+        return result;
+    }
+
     /// Emits an event of the given parameters in a synchronous fashion.
-    /// Instead of executing each callback in a newly spawned thread, it will execute each callback in the order that they were inserted.
+    /// Instead of executing each callback in a newly spawned thread, it will execute each callback
+    /// on the calling thread, in descending order of priority (ties broken by insertion order).
+    ///
+    /// If a listener was added with [`EventEmitter::on_with_priority`] and its callback returns
+    /// [`Propagation::Stop`], no remaining (lower-priority) listeners will be called for this emission.
+    /// Plain `on`/`on_limited`/`once` listeners always continue propagation. Listeners added with a
+    /// call limit (`on_limited`/`once`) have that limit honored and decremented here exactly as `emit`
+    /// does, and are removed once exhausted.
+    ///
+    /// Channels registered with [`EventEmitter::register`] are also fed the emitted value, just as
+    /// `emit` feeds them.
     ///
     /// # Example
     ///
@@ -344,9 +818,78 @@ impl EventEmitter {
     /// // The value can be of any type
     /// event_emitter.sync_emit("Some event", "Hello programmer!");
     /// ```
-    pub fn sync_emit<T>(&self, _event: &str, _value: T) 
+    pub fn sync_emit<T>(&mut self, event: &str, value: T)
         where T: Serialize
     {
-        unimplemented!()
+        let bytes: Vec<u8> = bincode::serialize(&value).unwrap();
+
+        if let Some(senders) = self.channels.lock().unwrap().get(event) {
+            for (_, sender) in senders {
+                let _ = sender.send(bytes.clone());
+            }
+        }
+
+        if let Some(listeners) = self.listeners.get_mut(event) {
+            let mut listeners_to_remove: Vec<usize> = Vec::new();
+
+            for (index, listener) in listeners.iter_mut().enumerate() {
+                let cloned_bytes = bytes.clone();
+
+                match listener.limit {
+                    None => {
+                        if let Propagation::Stop = (listener.callback)(cloned_bytes) {
+                            break;
+                        }
+                    },
+                    Some(limit) => {
+                        if limit != 0 {
+                            let propagation = (listener.callback)(cloned_bytes);
+                            listener.limit = Some(limit - 1);
+                            if let Propagation::Stop = propagation {
+                                break;
+                            }
+                        } else {
+                            listeners_to_remove.push(index);
+                        }
+                    }
+                }
+            }
+
+            // Reverse here so we don't mess up the ordering of the vector
+            for index in listeners_to_remove.into_iter().rev() {
+                listeners.remove(index);
+            }
+        }
+    }
+
+    /// Returns a future that resolves the next time `event` is emitted, yielding the emitted value -
+    /// The idiomatic async counterpart to `once`: instead of threading state through a callback and a
+    /// shared `Mutex`, a caller can simply write `let date: Date = emitter.wait_for("LOG_DATE").await;`.
+    ///
+    /// Internally this reuses the pull-based [`EventEmitter::register`] channel subscription rather
+    /// than a callback - Once the first value arrives, the `EventListener` is dropped and, via its
+    /// own `Drop` impl, immediately deregisters itself, so (unlike a plain `once` listener) nothing
+    /// lingers if the event never fires again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use event_emitter_rs::EventEmitter;
+    ///
+    /// # async fn run() {
+    /// let mut event_emitter = EventEmitter::new();
+    /// let waiter = event_emitter.wait_for::<u32>("Some event");
+    /// event_emitter.emit("Some event", 5 as u32);
+    /// assert_eq!(5 as u32, waiter.await);
+    /// # }
+    /// ```
+    pub fn wait_for<T>(&mut self, event: &str) -> impl Future<Output = T>
+        where for<'de> T: Deserialize<'de> + Send + 'static
+    {
+        let listener = self.register::<T>(event);
+
+        async move {
+            listener.recv()
+        }
     }
 }
\ No newline at end of file
@@ -114,6 +114,102 @@ fn once() {
 
 }
 
+#[test]
+fn sync_emit() {
+    let mut event_emitter = EventEmitter::new();
+    let order: Arc<Mutex<Vec<i32>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let cloned_order = Arc::clone(&order);
+    event_emitter.on("Ordered", move |_: ()| cloned_order.lock().unwrap().push(1));
+
+    let cloned_order = Arc::clone(&order);
+    event_emitter.on("Ordered", move |_: ()| cloned_order.lock().unwrap().push(2));
+
+    let cloned_order = Arc::clone(&order);
+    event_emitter.on("Ordered", move |_: ()| cloned_order.lock().unwrap().push(3));
+
+    event_emitter.sync_emit("Ordered", ());
+
+    assert_eq!(
+        vec![1, 2, 3],
+        *order.lock().unwrap(),
+        "Listeners should have run on the calling thread in insertion order"
+    );
+}
+
+#[test]
+fn sync_emit_priority_and_cancellation() {
+    use crate::Propagation;
+
+    let mut event_emitter = EventEmitter::new();
+    let order: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let cloned_order = Arc::clone(&order);
+    event_emitter.on_with_priority("Cancellable", 0, move |_: ()| {
+        cloned_order.lock().unwrap().push("low");
+        Propagation::Continue
+    });
+
+    let cloned_order = Arc::clone(&order);
+    event_emitter.on_with_priority("Cancellable", 10, move |_: ()| {
+        cloned_order.lock().unwrap().push("high");
+        Propagation::Continue
+    });
+
+    event_emitter.sync_emit("Cancellable", ());
+    assert_eq!(
+        vec!["high", "low"],
+        *order.lock().unwrap(),
+        "Higher priority listener should run before the lower priority one"
+    );
+
+    let mut event_emitter = EventEmitter::new();
+    let calls: Arc<Mutex<u32>> = Arc::new(Mutex::new(0));
+
+    let cloned_calls = Arc::clone(&calls);
+    event_emitter.on_with_priority("Stoppable", 10, move |_: ()| {
+        *cloned_calls.lock().unwrap() += 1;
+        Propagation::Stop
+    });
+    let cloned_calls = Arc::clone(&calls);
+    event_emitter.on_with_priority("Stoppable", 0, move |_: ()| {
+        *cloned_calls.lock().unwrap() += 1;
+        Propagation::Continue
+    });
+
+    event_emitter.sync_emit("Stoppable", ());
+    assert_eq!(1, *calls.lock().unwrap(), "Lower priority listener should be skipped once propagation is stopped");
+}
+
+#[test]
+fn sync_emit_honors_limit() {
+    let mut event_emitter = EventEmitter::new();
+    let calls: Arc<Mutex<u32>> = Arc::new(Mutex::new(0));
+
+    let cloned_calls = Arc::clone(&calls);
+    event_emitter.once("Set", move |_: ()| { *cloned_calls.lock().unwrap() += 1; });
+
+    event_emitter.sync_emit("Set", ());
+    assert_eq!(1, *calls.lock().unwrap(), "Listener should have run the first time");
+
+    event_emitter.sync_emit("Set", ());
+    assert_eq!(
+        1,
+        *calls.lock().unwrap(),
+        "A `once` listener reached through sync_emit should not run a second time"
+    );
+}
+
+#[test]
+fn sync_emit_feeds_registered_channels() {
+    let mut event_emitter = EventEmitter::new();
+
+    let listener = event_emitter.register::<u32>("Set");
+    event_emitter.sync_emit("Set", 10 as u32);
+
+    assert_eq!(10 as u32, listener.recv(), "register()'d channel should receive values dispatched through sync_emit too");
+}
+
 mod event_emitter_file {
     use std::sync::Mutex;
     use crate::EventEmitter;
@@ -129,4 +225,157 @@ fn global_emitter() {
 
     EVENT_EMITTER.lock().unwrap().on("Hello", |_: ()| println!("hello there!"));
     EVENT_EMITTER.lock().unwrap().emit("Hello", ());
+}
+
+#[test]
+fn register() {
+    let mut event_emitter = EventEmitter::new();
+
+    let listener = event_emitter.register::<u32>("Set");
+    event_emitter.emit("Set", 10 as u32);
+    assert_eq!(10 as u32, listener.recv(), "Listener should receive the emitted value");
+
+    assert_eq!(None, listener.try_recv(), "No value should be waiting after it's already been received");
+
+    event_emitter.emit("Set", 20 as u32);
+    assert_eq!(Some(20 as u32), listener.try_recv(), "try_recv should return the emitted value without blocking");
+}
+
+#[test]
+fn on_typed_and_emit_typed() {
+    let mut event_emitter = EventEmitter::new();
+    let counter: Arc<Mutex<u32>> = Arc::new(Mutex::new(0));
+
+    let cloned_counter = Arc::clone(&counter);
+    event_emitter.on_typed("Set", move |value: &u32| { *cloned_counter.lock().unwrap() = *value; });
+
+    event_emitter.emit_typed("Set", 42 as u32);
+
+    assert_eq!(
+        42 as u32,
+        *counter.lock().unwrap(),
+        "Typed listener should have received the value directly, with no serialization"
+    );
+}
+
+#[test]
+fn remove_typed_listener() {
+    let mut event_emitter = EventEmitter::new();
+    let counter: Arc<Mutex<u32>> = Arc::new(Mutex::new(0));
+
+    let cloned_counter = Arc::clone(&counter);
+    let listener_id = event_emitter.on_typed("Set", move |value: &u32| { *cloned_counter.lock().unwrap() = *value; });
+
+    event_emitter.remove_listener(&listener_id);
+    event_emitter.emit_typed("Set", 42 as u32);
+
+    assert_eq!(0, *counter.lock().unwrap(), "Removed typed listener should not have been called");
+}
+
+#[cfg(feature = "tokio_thread")]
+#[tokio::test]
+async fn on_async_and_emit_async() {
+    let mut event_emitter = EventEmitter::new();
+    let counter: Arc<Mutex<u32>> = Arc::new(Mutex::new(0));
+
+    let cloned_counter = Arc::clone(&counter);
+    event_emitter.on_async("Some event", move |value: u32| {
+        let cloned_counter = Arc::clone(&cloned_counter);
+        async move { *cloned_counter.lock().unwrap() = value; }
+    });
+
+    event_emitter.emit_async("Some event", 10 as u32).await;
+
+    assert_eq!(10 as u32, *counter.lock().unwrap(), "Async listener's future should have run to completion");
+}
+
+#[cfg(feature = "tokio_thread")]
+#[tokio::test]
+async fn remove_async_listener() {
+    let mut event_emitter = EventEmitter::new();
+    let counter: Arc<Mutex<u32>> = Arc::new(Mutex::new(0));
+
+    let cloned_counter = Arc::clone(&counter);
+    let listener_id = event_emitter.on_async("Some event", move |value: u32| {
+        let cloned_counter = Arc::clone(&cloned_counter);
+        async move { *cloned_counter.lock().unwrap() = value; }
+    });
+
+    event_emitter.remove_listener(&listener_id);
+    event_emitter.emit_async("Some event", 10 as u32).await;
+
+    assert_eq!(0, *counter.lock().unwrap(), "Removed async listener should not have run");
+}
+
+#[test]
+fn on_try_and_try_emit() {
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct MyError;
+    impl fmt::Display for MyError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "something went wrong") }
+    }
+    impl std::error::Error for MyError {}
+
+    let mut event_emitter = EventEmitter::new();
+    event_emitter.on_try("Some event", |_: ()| -> Result<(), MyError> { Ok(()) });
+    event_emitter.on_try("Some event", |_: ()| -> Result<(), MyError> { Err(MyError) });
+
+    let result = event_emitter.try_emit("Some event", ());
+
+    assert_eq!(2, result.listeners_invoked, "Both on_try listeners should have been invoked");
+    assert_eq!(1, result.errors.len(), "Only the failing listener's error should be reported");
+}
+
+#[test]
+fn try_emit_surfaces_panics() {
+    let mut event_emitter = EventEmitter::new();
+    event_emitter.on_try("Some event", |_: ()| -> Result<(), std::io::Error> { panic!("boom") });
+
+    let result = event_emitter.try_emit("Some event", ());
+
+    assert_eq!(1, result.listeners_invoked, "The panicking listener should still count as invoked");
+    assert_eq!(1, result.errors.len(), "A panicking listener's failure should be reported as an error");
+}
+
+#[test]
+fn remove_try_listener() {
+    let mut event_emitter = EventEmitter::new();
+    let listener_id = event_emitter.on_try("Some event", |_: ()| -> Result<(), std::io::Error> { Ok(()) });
+
+    event_emitter.remove_listener(&listener_id);
+    let result = event_emitter.try_emit("Some event", ());
+
+    assert_eq!(0, result.listeners_invoked, "Removed try listener should not have been invoked");
+}
+
+#[test]
+fn wait_for() {
+    let mut event_emitter = EventEmitter::new();
+
+    let waiter = event_emitter.wait_for::<u32>("Some event");
+    assert_eq!(1, event_emitter.channels.lock().unwrap().get("Some event").unwrap().len());
+
+    event_emitter.emit("Some event", 10 as u32);
+
+    let value = futures::executor::block_on(waiter);
+    assert_eq!(10 as u32, value, "wait_for should resolve with the next emitted value");
+
+    assert_eq!(
+        0,
+        event_emitter.channels.lock().unwrap().get("Some event").unwrap().len(),
+        "wait_for's listener should deregister itself as soon as the future resolves, not lazily on a later emission"
+    );
+}
+
+#[test]
+fn register_drop_deregisters() {
+    let mut event_emitter = EventEmitter::new();
+
+    let listener = event_emitter.register::<u32>("Set");
+    assert_eq!(1, event_emitter.channels.lock().unwrap().get("Set").unwrap().len());
+
+    drop(listener);
+    assert_eq!(0, event_emitter.channels.lock().unwrap().get("Set").unwrap().len(), "Dropping the listener should deregister its channel");
 }
\ No newline at end of file